@@ -1,491 +1,742 @@
-use std::{rc::{Rc, Weak}, cell::{RefCell, Ref, RefMut}, fmt};
-use std::ops;
-use ndarray::{ArrayD, Array, Dimension};
-use num_traits::{Float};
-
-pub struct VariableInternal<'c, A: Float> {
-    pub data: ArrayD<A>,
-    pub grad: Option<ArrayD<A>>,
-    generation: usize,
-    creator: Option<Rc<Creator<'c,A>>>,
-}
-
-impl<'c, A:Float> VariableInternal<'c,A>{
-    pub fn new<D:Dimension>(data: Array<A, D>) -> Self{
-        let data = data.into_dyn();
-        VariableInternal {
-            data,
-            grad: None,
-            generation: 0,
-            creator: None
-        }
-    }
-
-    pub fn backward(&self){
-        if let Some(c) = &self.creator{
-            let mut creators = vec![Rc::clone(c)];
-            let mut seen_set = vec![Rc::clone(c)];
-            loop{
-                if creators.is_empty(){
-                    break;
-                }
-                if let Some(c) = creators.pop(){
-                    let gys = c.outputs.iter().map(|output|{
-                        let output = output.upgrade().unwrap();
-                        let output = output.borrow();
-                        match output.grad.as_ref(){
-                            Some(g) => g.clone(),
-                            None => ArrayD::ones(output.data.dim()),
-                        }
-                    }).collect::<Vec<_>>();
-
-                    let gxs = c.function.backward(
-                        &c.inputs
-                            .iter()
-                            .map(|input| 
-                                Ref::map(input.borrow(),|i| &i.data)
-                            ).collect::<Vec<_>>(),
-                        &gys
-                    );
-                    c.inputs.iter().zip(gxs).for_each(|(input,gx)|{
-                        let mut input = input.borrow_mut();
-                        input.grad = match &input.grad{
-                            Some(g) => Some(g + gx.clone()),
-                            None => Some(gx.clone()),
-                        };
-                        if let Some(ic) = &input.creator{
-                            if let None = seen_set.iter().find(|s| Rc::ptr_eq(*s, ic)){
-                                creators.push(Rc::clone(ic));
-                                seen_set.push(Rc::clone(ic));
-                                creators.sort_by(|a, b| a.generation.cmp(&b.generation));
-                            }
-                        }
-                    });
-                }
-            }
-        }
-    }
-}
-
-#[derive(Clone)]
-pub struct Variable<'c, A: Float> {
-    internal: Rc<RefCell<VariableInternal<'c, A>>>
-}
-
-impl<'c, A: Float> Variable<'c, A>{
-    pub fn new<D:Dimension>(data: Array<A, D>) -> Self{
-        let internal = VariableInternal::new(data);
-        Variable{
-            internal: Rc::new(RefCell::new(internal))
-        }
-    }
-    
-    pub fn data(&self) -> Ref<ArrayD<A>>{
-        Ref::map(self.internal.borrow(), |i| &i.data)
-    }
-
-    pub fn data_mut(&self) -> RefMut<ArrayD<A>>{
-        RefMut::map(self.internal.borrow_mut(), |i| &mut i.data)
-    }
-
-    pub fn grad(&self) -> Ref<Option<ArrayD<A>>>{
-        Ref::map(self.internal.borrow(), |i| &i.grad)
-    }
-
-    pub fn grad_mut(&self) -> RefMut<Option<ArrayD<A>>>{
-        RefMut::map(self.internal.borrow_mut(), |i| &mut i.grad)
-    }
-
-    pub fn generation(&self) -> usize{
-        self.internal.borrow().generation
-    }
-
-    pub fn backward(&self){
-        self.internal.borrow().backward();
-    }
-
-    pub fn cleargrad(&self){
-        self.internal.borrow_mut().grad = None;
-    }
-
-    pub fn len(&self) -> usize{
-        self.internal.borrow().data.len()
-    }
-
-    pub fn powf(&self, c:f64) -> Variable<'c, A>{
-        powf(&self,c)
-    }
-
-    fn set_creator(&self, creator: Creator<'c, A>){
-        let mut internal = self.internal.borrow_mut();
-        internal.generation = &creator.generation + 1;
-        internal.creator = Some(Rc::new(creator));
-         
-    }
-}
-
-impl<'c, A:Float + fmt::Display> fmt::Display for Variable<'c, A>{
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "variable({})", self.data())
-    }
-}
-
-struct Creator<'c, A: Float>{
-    inputs: Vec<Rc<RefCell<VariableInternal<'c, A>>>>,
-    outputs: Vec<Weak<RefCell<VariableInternal<'c, A>>>>,
-    generation: usize,
-    function: Rc<dyn 'c + Function<A>>,
-}
-
-pub trait Function<A: Float>{
-    fn call<'c>(self, inputs: &[&Variable<'c, A>]) -> Vec<Variable<'c, A>>
-    where Self: 'c + Sized
-    {
-        let xs = &inputs.iter().map(|input| input.data()).collect::<Vec<_>>();
-        let generation = &inputs.iter().map(|input| input.generation()).max().unwrap();
-        let ys = self.forward(xs);
-        let outputs = ys.iter().map(|y|Variable::new(y.clone())).collect::<Vec<_>>();
-        let function:Rc<dyn Function<A>>= Rc::new(self);
-        outputs.iter().for_each(|output|{
-            output.set_creator(Creator{
-                inputs: inputs.iter().map(|input| Rc::clone(&input.internal)).collect::<Vec<_>>(),
-                outputs: outputs.iter().map(|output| Rc::downgrade(&output.internal)).collect::<Vec<_>>(),
-                generation: *generation,
-                function: Rc::clone(&function),
-            });
-        });
-        outputs
-    }
-
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>>;
-    fn backward(&self, xs: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>>;
-}
-
-//arithmetic operations
-//add
-struct Add;
-impl<A: Float> Function<A> for Add{
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
-        let y = &(*xs[0]) + &(*xs[1]);
-        vec![y]
-    }
-
-    fn backward(&self, _: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
-        vec![gys[0].clone(), gys[0].clone()]
-    }
-}
-
-fn add<'c, A:Float>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
-    Add.call(&[x0, x1])[0].clone()
-}
-
-impl <'c, A:Float> ops::Add<&Variable<'c, A>> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn add(self, x: &Variable<'c, A>) -> Variable<'c, A>{
-        add(&self, &x)
-    }
-}
-
-impl <'c, A:Float, D:Dimension> ops::Add<&Array<A, D>> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn add(self, x: &Array<A, D>) -> Variable<'c, A>{
-        let x = Variable::new(x.clone());
-        add(&self, &x)
-    }
-}
-
-impl <'c, A:Float, D:Dimension> ops::Add<&Variable<'c, A>> for &Array<A, D>{
-    type Output = Variable<'c, A>;
-    fn add(self, x: &Variable<'c, A>) -> Variable<'c, A>{
-        let x0 = Variable::new(self.clone());
-        add(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Add<A> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn add(self, x: A) -> Self::Output {
-        let x = Variable::new(Array::from_elem(self.data().dim(),x));
-        add(&self, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Add<&Variable<'c, A>> for f64{
-    type Output = Variable<'c, A>;
-    fn add(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        add(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Add<&Variable<'c, A>> for f32{
-    type Output = Variable<'c, A>;
-    fn add(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        add(&x0, &x)
-    }
-}
-
-//mul
-struct Mul;
-impl<A: Float> Function<A> for Mul{
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
-        let x0 = &(*xs[0]);
-        let x1 = &(*xs[1]);
-        let y = x0 * x1;
-        vec![y]
-    }
-
-    fn backward(&self, xs: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
-        let x0 = &(*xs[0]);
-        let x1 = &(*xs[1]); 
-        let gy = &gys[0];
-        vec![gy * x1, gy * x0]
-    }
-}
-
-fn mul<'c, A:Float>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
-    Mul.call(&[x0, x1])[0].clone()
-}
-
-impl<'c, A:Float> ops::Mul for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn mul(self, x: Self) -> Self::Output {
-        mul(&self, &x)
-    }
-}
-
-impl<'c, A:Float, D:Dimension> ops::Mul<&Array<A, D>> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-
-    fn mul(self, x: &Array<A, D>) -> Self::Output {
-        let x = Variable::new(x.clone());
-        mul(&self, &x)
-    }
-}
-
-impl<'c, A:Float, D:Dimension> ops::Mul<&Variable<'c, A>> for &Array<A, D>{
-    type Output = Variable<'c, A>;
-    fn mul(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(self.clone());
-        mul(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Mul<A> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn mul(self, x: A) -> Self::Output {
-        let x = Variable::new(Array::from_elem(self.data().dim(),x));
-        mul(&self, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Mul<&Variable<'c, A>> for f64{
-    type Output = Variable<'c, A>;
-    fn mul(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        mul(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Mul<&Variable<'c, A>> for f32{
-    type Output = Variable<'c, A>;
-    fn mul(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        mul(&x0, &x)
-    }
-}
-
-//neg
-struct Neg;
-impl<A: Float> Function<A> for Neg{
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
-        let x = &xs[0];
-        let y = x.mapv(|x| -x);
-        vec![y]
-    }
-
-    fn backward(&self, _: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
-        let gy = &gys[0];
-        let gx = gy.mapv(|gy| -gy);
-        vec![gx]
-    }
-}
-
-fn neg<'c, A:Float>(x: &Variable<'c, A>) -> Variable<'c, A>{
-    Neg.call(&[x])[0].clone()
-}
-
-impl<'c, A:Float> ops::Neg for Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn neg(self) -> Self::Output {
-        neg(&self)
-    }
-}
-
-//sub
-struct Sub;
-impl<A:Float> Function<A> for Sub{
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
-        let y = &(*xs[0]) - &(*xs[1]);
-        vec![y]
-    }
-
-    fn backward(&self, _: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
-        vec![gys[0].clone(), -gys[0].clone()]
-    }
-}
-
-fn sub<'c, A:Float>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
-    Sub.call(&[x0, x1])[0].clone()
-}
-
-impl<'c, A:Float> ops::Sub for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn sub(self, x: Self) -> Self::Output {
-        sub(&self, &x)
-    }
-}
-
-impl<'c, A:Float, D: Dimension> ops::Sub<&Array<A, D>> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn sub(self, x: &Array<A, D>) -> Self::Output {
-        let x = Variable::new(x.clone());
-        sub(&self, &x)
-    }
-}
-
-impl<'c, A:Float, D: Dimension> ops::Sub<&Variable<'c, A>> for &Array<A, D>{
-    type Output = Variable<'c, A>;
-    fn sub(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(self.clone());
-        sub(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Sub<A> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn sub(self, x: A) -> Self::Output {
-        let x = Variable::new(Array::from_elem(self.data().dim(),x));
-        sub(&self, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Sub<&Variable<'c, A>> for f64{
-    type Output = Variable<'c, A>;
-    fn sub(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        sub(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Sub<&Variable<'c, A>> for f32{
-    type Output = Variable<'c, A>;
-    fn sub(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        sub(&x0, &x)
-    }
-}
-
-
-//div
-struct Div;
-impl<A: Float> Function<A> for Div{
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
-        let x0 = &(*xs[0]);
-        let x1 = &(*xs[1]);
-        let y = x0 / x1;
-        vec![y]
-    }
-
-    fn backward(&self, xs: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
-        let x0 = &(*xs[0]);
-        let x1 = &(*xs[1]); 
-        let gy = &gys[0];
-        let gx0 = gy / x1;
-        let gx1 = x0.mapv(|x0| -x0) / x1.mapv(|x1| x1.powi(2)) * gy; 
-        vec![gx0, gx1]
-    }
-}
-
-fn div<'c, A:Float>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
-    Div.call(&[x0,x1])[0].clone()
-}
-
-impl<'c, A:Float> ops::Div for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn div(self, x: Self) -> Self::Output {
-        div(&self, &x)
-    }
-}
-
-impl<'c, A:Float, D: Dimension> ops::Div<&Array<A, D>> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn div(self, x: &Array<A, D>) -> Self::Output {
-        let x = Variable::new(x.clone());
-        div(&self, &x)
-    }
-}
-
-impl<'c, A:Float, D: Dimension> ops::Div<&Variable<'c, A>> for &Array<A, D>{
-    type Output = Variable<'c, A>;
-    fn div(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(self.clone());
-        div(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Div<A> for &Variable<'c, A>{
-    type Output = Variable<'c, A>;
-    fn div(self, x: A) -> Self::Output {
-        let x = Variable::new(Array::from_elem(self.data().dim(),x));
-        div(&self, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Div<&Variable<'c, A>> for f64{
-    type Output = Variable<'c, A>;
-    fn div(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        div(&x0, &x)
-    }
-}
-
-impl<'c, A:Float> ops::Div<&Variable<'c, A>> for f32{
-    type Output = Variable<'c, A>;
-    fn div(self, x: &Variable<'c, A>) -> Self::Output {
-        let x0 = Variable::new(Array::from_elem(x.data().dim(),A::from(self).unwrap()));
-        div(&x0, &x)
-    }
-}
-
-//powf
-struct Powf{
-    c: f64
-}
-
-impl<A: Float> Function<A> for Powf{
-    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
-        let x = &xs[0];
-        let c = A::from(self.c).unwrap(); 
-        let y = x.mapv(|x| x.powf(c));
-        vec![y]
-    }
-
-    fn backward(&self, xs: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
-        let x = &xs[0];
-        let gy = &gys[0];
-        let c = A::from(self.c).unwrap();
-        let gx = x.mapv(|x| x.powf(c - A::from(1).unwrap()) * c) * gy;
-        vec![gx]
-    }
-}
-
-fn powf<'c, A: Float>(input: &Variable<'c, A>, c:f64) -> Variable<'c, A>{
-    Powf{c}.call(&[input])[0].clone()
-}
-
-#[test]
-fn test(){
-    
+use std::{rc::{Rc, Weak}, cell::{RefCell, Ref, RefMut}, fmt};
+use std::collections::{BinaryHeap, HashSet};
+use std::ops;
+use ndarray::{ArrayD, Array, Array1, Array2, Dimension, Axis, IxDyn};
+use num_traits::{Float};
+
+// The ring operations the autodiff graph itself needs: construction,
+// `backward`, and the basic arithmetic `Function`s `Add`/`Sub`/`Mul`/`Neg`
+// only ever add, subtract, multiply, or negate `ArrayD` elements, never
+// divide. `Div`'s `Function` impl (and its operator glue) adds its own
+// `ops::Div<Output = Self>` bound on top of this trait instead, so a
+// division-free scalar type (e.g. a modular-arithmetic `ModInt` with
+// `Mod::P` and u64-reduced `Add`/`Mul`/`Neg`, but no `Div`) can still ride
+// the rest of the graph. `Exp`/`Powf`/`Square` need transcendental math and
+// stay bound to the stronger `num_traits::Float` instead. Any `Float` is
+// automatically a `Scalar`.
+pub trait Scalar:
+    Clone
+    + ops::Add<Output = Self>
+    + ops::Sub<Output = Self>
+    + ops::Mul<Output = Self>
+    + ops::Neg<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+impl<A: Float> Scalar for A {
+    fn zero() -> Self { <A as num_traits::Zero>::zero() }
+    fn one() -> Self { <A as num_traits::One>::one() }
+}
+
+thread_local! {
+    // Mirrors dezero's `enable_backprop` config: while set (the default),
+    // `Function::call` records a creator. `backward` sets it to its
+    // `create_graph` argument for the traversal and restores it afterwards, so
+    // first-order `backward(false)` doesn't pay for a graph over the grads.
+    static ENABLE_BACKPROP: std::cell::Cell<bool> = std::cell::Cell::new(true);
+}
+
+//broadcasting helpers shared by the arithmetic Functions and by SumTo/BroadcastTo
+fn sum_axis_scalar<A: Scalar>(x: &ArrayD<A>, axis: Axis) -> ArrayD<A> {
+    x.fold_axis(axis, A::zero(), |acc, v| acc.clone() + v.clone())
+}
+
+fn sum_to_shape<A: Scalar>(x: &ArrayD<A>, shape: &[usize]) -> ArrayD<A> {
+    let mut y = x.clone();
+    while y.ndim() > shape.len() {
+        y = sum_axis_scalar(&y, Axis(0));
+    }
+    for (axis, &s) in shape.iter().enumerate() {
+        if s == 1 && y.shape()[axis] != 1 {
+            y = sum_axis_scalar(&y, Axis(axis)).insert_axis(Axis(axis));
+        }
+    }
+    y.into_shape(IxDyn(shape)).unwrap()
+}
+
+fn broadcast_to_shape<A: Scalar>(x: &ArrayD<A>, shape: &[usize]) -> ArrayD<A> {
+    x.broadcast(IxDyn(shape))
+        .unwrap_or_else(|| panic!("BroadcastTo: cannot broadcast shape {:?} to {:?}", x.shape(), shape))
+        .to_owned()
+}
+
+pub struct VariableInternal<'c, A: Scalar> {
+    pub data: ArrayD<A>,
+    pub grad: Option<Variable<'c, A>>,
+    generation: usize,
+    creator: Option<Rc<Creator<'c,A>>>,
+}
+
+impl<'c, A:Scalar> VariableInternal<'c,A>{
+    pub fn new<D:Dimension>(data: Array<A, D>) -> Self{
+        let data = data.into_dyn();
+        VariableInternal {
+            data,
+            grad: None,
+            generation: 0,
+            creator: None
+        }
+    }
+
+    // `create_graph` mirrors dezero's `enable_backprop` config: when true, the
+    // gradient computations below are themselves routed through `Function::call`,
+    // so a creator graph is recorded over the grads and `x.grad().backward()`
+    // can take a second derivative. When false (the common case) no such graph
+    // is built, so grads are cheap, detached `Variable`s.
+    //
+    // Takes `creator` by value rather than `&self`: the traversal below clears
+    // grads (including, on the first iteration, possibly `self`'s own grad, if
+    // `self` is itself one of its creator's outputs), which would panic on the
+    // `RefCell` borrow against a `Ref` still held on `self.internal` if this
+    // borrowed `self` for the whole call.
+    fn backward(creator: Option<Rc<Creator<'c, A>>>, create_graph: bool){
+        if let Some(c) = &creator{
+            let prev_enable_backprop = ENABLE_BACKPROP.with(|e| e.replace(create_graph));
+            // `Creator`'s `Ord` is by `generation`, so this max-heap always pops the
+            // deepest not-yet-processed creator, preserving the reverse-topological
+            // order gradient accumulation needs without re-sorting on every push.
+            // `seen_set` dedupes by `Rc` pointer address instead of a linear scan.
+            let mut creators = BinaryHeap::new();
+            let mut seen_set = HashSet::new();
+            creators.push(Rc::clone(c));
+            seen_set.insert(Rc::as_ptr(c) as usize);
+            while let Some(c) = creators.pop(){
+                let gys = c.outputs.iter().map(|output|{
+                    let output = output.upgrade().unwrap();
+                    let grad = output.borrow().grad.clone();
+                    match grad{
+                        Some(g) => g,
+                        None => Variable::new(ArrayD::from_elem(output.borrow().data.dim(), A::one())),
+                    }
+                }).collect::<Vec<_>>();
+
+                let gxs = c.function.backward(&c.inputs, &gys);
+
+                c.inputs.iter().zip(gxs).for_each(|(input,gx)|{
+                    let grad = input.internal.borrow().grad.clone();
+                    let new_grad = match grad{
+                        Some(g) => &g + &gx,
+                        None => gx,
+                    };
+                    input.internal.borrow_mut().grad = Some(new_grad);
+                    let creator = input.internal.borrow().creator.clone();
+                    if let Some(ic) = creator{
+                        if seen_set.insert(Rc::as_ptr(&ic) as usize){
+                            creators.push(ic);
+                        }
+                    }
+                });
+
+                // Matches dezero's `retain_grad=False`: once `c`'s outputs have been
+                // read as `gys` above, their `grad` is done being needed for this
+                // traversal. Clearing it here (rather than leaving it set) stops a
+                // stale grad from a prior `backward` call silently being added to on
+                // the next one - these outputs are never leaves, so nothing else
+                // still needs their grad afterwards.
+                c.outputs.iter().for_each(|output|{
+                    if let Some(output) = output.upgrade(){
+                        output.borrow_mut().grad = None;
+                    }
+                });
+            }
+            ENABLE_BACKPROP.with(|e| e.set(prev_enable_backprop));
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct Variable<'c, A: Scalar> {
+    internal: Rc<RefCell<VariableInternal<'c, A>>>
+}
+
+impl<'c, A: Scalar> Variable<'c, A>{
+    pub fn new<D:Dimension>(data: Array<A, D>) -> Self{
+        let internal = VariableInternal::new(data);
+        Variable{
+            internal: Rc::new(RefCell::new(internal))
+        }
+    }
+
+    pub fn data(&self) -> Ref<ArrayD<A>>{
+        Ref::map(self.internal.borrow(), |i| &i.data)
+    }
+
+    pub fn data_mut(&self) -> RefMut<ArrayD<A>>{
+        RefMut::map(self.internal.borrow_mut(), |i| &mut i.data)
+    }
+
+    pub fn grad(&self) -> Ref<Option<Variable<'c, A>>>{
+        Ref::map(self.internal.borrow(), |i| &i.grad)
+    }
+
+    pub fn grad_mut(&self) -> RefMut<Option<Variable<'c, A>>>{
+        RefMut::map(self.internal.borrow_mut(), |i| &mut i.grad)
+    }
+
+    pub fn generation(&self) -> usize{
+        self.internal.borrow().generation
+    }
+
+    pub fn backward(&self, create_graph: bool){
+        let creator = self.internal.borrow().creator.clone();
+        VariableInternal::backward(creator, create_graph);
+    }
+
+    pub fn cleargrad(&self){
+        self.internal.borrow_mut().grad = None;
+    }
+
+    pub fn len(&self) -> usize{
+        self.internal.borrow().data.len()
+    }
+
+    fn set_creator(&self, creator: Creator<'c, A>){
+        let mut internal = self.internal.borrow_mut();
+        internal.generation = &creator.generation + 1;
+        internal.creator = Some(Rc::new(creator));
+
+    }
+}
+
+impl<'c, A: Float> Variable<'c, A>{
+    pub fn powf(&self, c:f64) -> Variable<'c, A>{
+        powf(&self,c)
+    }
+}
+
+impl<'c, A:Scalar + fmt::Display> fmt::Display for Variable<'c, A>{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "variable({})", self.data())
+    }
+}
+
+struct Creator<'c, A: Scalar>{
+    inputs: Vec<Variable<'c, A>>,
+    outputs: Vec<Weak<RefCell<VariableInternal<'c, A>>>>,
+    generation: usize,
+    function: Rc<dyn 'c + Function<A>>,
+}
+
+// Ordered by `generation` alone, so a `BinaryHeap<Rc<Creator>>` pops the
+// deepest creator first during `backward`'s graph traversal.
+impl<'c, A: Scalar> PartialEq for Creator<'c, A>{
+    fn eq(&self, other: &Self) -> bool{
+        self.generation == other.generation
+    }
+}
+
+impl<'c, A: Scalar> Eq for Creator<'c, A>{}
+
+impl<'c, A: Scalar> PartialOrd for Creator<'c, A>{
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering>{
+        Some(self.cmp(other))
+    }
+}
+
+impl<'c, A: Scalar> Ord for Creator<'c, A>{
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering{
+        self.generation.cmp(&other.generation)
+    }
+}
+
+pub trait Function<A: Scalar>{
+    fn call<'c>(self, inputs: &[&Variable<'c, A>]) -> Vec<Variable<'c, A>>
+    where Self: 'c + Sized
+    {
+        let xs = &inputs.iter().map(|input| input.data()).collect::<Vec<_>>();
+        let ys = self.forward(xs);
+        let outputs = ys.iter().map(|y|Variable::new(y.clone())).collect::<Vec<_>>();
+        if ENABLE_BACKPROP.with(|e| e.get()){
+            let generation = &inputs.iter().map(|input| input.generation()).max().unwrap();
+            let function:Rc<dyn Function<A>>= Rc::new(self);
+            outputs.iter().for_each(|output|{
+                output.set_creator(Creator{
+                    inputs: inputs.iter().map(|input| (*input).clone()).collect::<Vec<_>>(),
+                    outputs: outputs.iter().map(|output| Rc::downgrade(&output.internal)).collect::<Vec<_>>(),
+                    generation: *generation,
+                    function: Rc::clone(&function),
+                });
+            });
+        }
+        outputs
+    }
+
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>>;
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>>;
+}
+
+//arithmetic operations
+//add
+struct Add;
+impl<A: Scalar> Function<A> for Add{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let y = &(*xs[0]) + &(*xs[1]);
+        vec![y]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let gy = &gys[0];
+        let shape0 = xs[0].data().shape().to_vec();
+        let shape1 = xs[1].data().shape().to_vec();
+        vec![sum_to(gy, &shape0), sum_to(gy, &shape1)]
+    }
+}
+
+fn add<'c, A:Scalar>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
+    Add.call(&[x0, x1])[0].clone()
+}
+
+impl <'c, A:Scalar> ops::Add<&Variable<'c, A>> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn add(self, x: &Variable<'c, A>) -> Variable<'c, A>{
+        add(&self, &x)
+    }
+}
+
+impl <'c, A:Scalar, D:Dimension> ops::Add<&Array<A, D>> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn add(self, x: &Array<A, D>) -> Variable<'c, A>{
+        let x = Variable::new(x.clone());
+        add(&self, &x)
+    }
+}
+
+impl <'c, A:Scalar, D:Dimension> ops::Add<&Variable<'c, A>> for &Array<A, D>{
+    type Output = Variable<'c, A>;
+    fn add(self, x: &Variable<'c, A>) -> Variable<'c, A>{
+        let x0 = Variable::new(self.clone());
+        add(&x0, &x)
+    }
+}
+
+impl<'c, A:Scalar> ops::Add<A> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn add(self, x: A) -> Self::Output {
+        let x = Variable::new(Array::from_elem((), x));
+        add(&self, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Add<&Variable<'c, A>> for f64{
+    type Output = Variable<'c, A>;
+    fn add(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        add(&x0, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Add<&Variable<'c, A>> for f32{
+    type Output = Variable<'c, A>;
+    fn add(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        add(&x0, &x)
+    }
+}
+
+//mul
+struct Mul;
+impl<A: Scalar> Function<A> for Mul{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x0 = &(*xs[0]);
+        let x1 = &(*xs[1]);
+        let y = x0 * x1;
+        vec![y]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let x0 = &xs[0];
+        let x1 = &xs[1];
+        let gy = &gys[0];
+        let shape0 = x0.data().shape().to_vec();
+        let shape1 = x1.data().shape().to_vec();
+        vec![sum_to(&(gy * x1), &shape0), sum_to(&(gy * x0), &shape1)]
+    }
+}
+
+fn mul<'c, A:Scalar>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
+    Mul.call(&[x0, x1])[0].clone()
+}
+
+impl<'c, A:Scalar> ops::Mul for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn mul(self, x: Self) -> Self::Output {
+        mul(&self, &x)
+    }
+}
+
+impl<'c, A:Scalar, D:Dimension> ops::Mul<&Array<A, D>> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+
+    fn mul(self, x: &Array<A, D>) -> Self::Output {
+        let x = Variable::new(x.clone());
+        mul(&self, &x)
+    }
+}
+
+impl<'c, A:Scalar, D:Dimension> ops::Mul<&Variable<'c, A>> for &Array<A, D>{
+    type Output = Variable<'c, A>;
+    fn mul(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(self.clone());
+        mul(&x0, &x)
+    }
+}
+
+impl<'c, A:Scalar> ops::Mul<A> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn mul(self, x: A) -> Self::Output {
+        let x = Variable::new(Array::from_elem((), x));
+        mul(&self, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Mul<&Variable<'c, A>> for f64{
+    type Output = Variable<'c, A>;
+    fn mul(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        mul(&x0, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Mul<&Variable<'c, A>> for f32{
+    type Output = Variable<'c, A>;
+    fn mul(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        mul(&x0, &x)
+    }
+}
+
+//neg
+struct Neg;
+impl<A: Scalar> Function<A> for Neg{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = &xs[0];
+        let y = x.mapv(|x| -x);
+        vec![y]
+    }
+
+    fn backward<'c>(&self, _: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        vec![-(gys[0].clone())]
+    }
+}
+
+fn neg<'c, A:Scalar>(x: &Variable<'c, A>) -> Variable<'c, A>{
+    Neg.call(&[x])[0].clone()
+}
+
+impl<'c, A:Scalar> ops::Neg for Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn neg(self) -> Self::Output {
+        neg(&self)
+    }
+}
+
+//sub
+struct Sub;
+impl<A:Scalar> Function<A> for Sub{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let y = &(*xs[0]) - &(*xs[1]);
+        vec![y]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let gy = &gys[0];
+        let shape0 = xs[0].data().shape().to_vec();
+        let shape1 = xs[1].data().shape().to_vec();
+        vec![sum_to(gy, &shape0), sum_to(&-(gy.clone()), &shape1)]
+    }
+}
+
+fn sub<'c, A:Scalar>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
+    Sub.call(&[x0, x1])[0].clone()
+}
+
+impl<'c, A:Scalar> ops::Sub for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn sub(self, x: Self) -> Self::Output {
+        sub(&self, &x)
+    }
+}
+
+impl<'c, A:Scalar, D: Dimension> ops::Sub<&Array<A, D>> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn sub(self, x: &Array<A, D>) -> Self::Output {
+        let x = Variable::new(x.clone());
+        sub(&self, &x)
+    }
+}
+
+impl<'c, A:Scalar, D: Dimension> ops::Sub<&Variable<'c, A>> for &Array<A, D>{
+    type Output = Variable<'c, A>;
+    fn sub(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(self.clone());
+        sub(&x0, &x)
+    }
+}
+
+impl<'c, A:Scalar> ops::Sub<A> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn sub(self, x: A) -> Self::Output {
+        let x = Variable::new(Array::from_elem((), x));
+        sub(&self, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Sub<&Variable<'c, A>> for f64{
+    type Output = Variable<'c, A>;
+    fn sub(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        sub(&x0, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Sub<&Variable<'c, A>> for f32{
+    type Output = Variable<'c, A>;
+    fn sub(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        sub(&x0, &x)
+    }
+}
+
+
+//div
+//`Div` is the one arithmetic `Function` that needs `A: ops::Div` on top of
+//`Scalar` (see `Scalar`'s doc comment) - a `Scalar` that never implements
+//`Div` rides the rest of the graph fine but simply can't be used here.
+struct Div;
+impl<A: Scalar + ops::Div<Output = A>> Function<A> for Div{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x0 = &(*xs[0]);
+        let x1 = &(*xs[1]);
+        let y = x0 / x1;
+        vec![y]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let x0 = &xs[0];
+        let x1 = &xs[1];
+        let gy = &gys[0];
+        let gx0 = gy / x1;
+        let neg_gy_x0 = -(gy * x0);
+        let x1_sq = x1 * x1;
+        let gx1 = &neg_gy_x0 / &x1_sq;
+        let shape0 = x0.data().shape().to_vec();
+        let shape1 = x1.data().shape().to_vec();
+        vec![sum_to(&gx0, &shape0), sum_to(&gx1, &shape1)]
+    }
+}
+
+fn div<'c, A:Scalar + ops::Div<Output = A>>(x0: &Variable<'c, A>, x1: &Variable<'c, A>) -> Variable<'c, A>{
+    Div.call(&[x0,x1])[0].clone()
+}
+
+impl<'c, A:Scalar + ops::Div<Output = A>> ops::Div for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn div(self, x: Self) -> Self::Output {
+        div(&self, &x)
+    }
+}
+
+impl<'c, A:Scalar + ops::Div<Output = A>, D: Dimension> ops::Div<&Array<A, D>> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn div(self, x: &Array<A, D>) -> Self::Output {
+        let x = Variable::new(x.clone());
+        div(&self, &x)
+    }
+}
+
+impl<'c, A:Scalar + ops::Div<Output = A>, D: Dimension> ops::Div<&Variable<'c, A>> for &Array<A, D>{
+    type Output = Variable<'c, A>;
+    fn div(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(self.clone());
+        div(&x0, &x)
+    }
+}
+
+impl<'c, A:Scalar + ops::Div<Output = A>> ops::Div<A> for &Variable<'c, A>{
+    type Output = Variable<'c, A>;
+    fn div(self, x: A) -> Self::Output {
+        let x = Variable::new(Array::from_elem((), x));
+        div(&self, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Div<&Variable<'c, A>> for f64{
+    type Output = Variable<'c, A>;
+    fn div(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        div(&x0, &x)
+    }
+}
+
+impl<'c, A:Float> ops::Div<&Variable<'c, A>> for f32{
+    type Output = Variable<'c, A>;
+    fn div(self, x: &Variable<'c, A>) -> Self::Output {
+        let x0 = Variable::new(Array::from_elem((), A::from(self).unwrap()));
+        div(&x0, &x)
+    }
+}
+
+//powf
+struct Powf{
+    c: f64
+}
+
+impl<A: Float> Function<A> for Powf{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = &xs[0];
+        let c = A::from(self.c).unwrap(); 
+        let y = x.mapv(|x| x.powf(c));
+        vec![y]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let x = &xs[0];
+        let gy = &gys[0];
+        let c = A::from(self.c).unwrap();
+        let gx = &(&x.powf(self.c - 1.0) * c) * gy;
+        vec![gx]
+    }
+}
+
+fn powf<'c, A: Float>(input: &Variable<'c, A>, c:f64) -> Variable<'c, A>{
+    Powf{c}.call(&[input])[0].clone()
+}
+
+//broadcast_to
+struct BroadcastTo{
+    shape: Vec<usize>
+}
+
+impl<A: Scalar> Function<A> for BroadcastTo{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        vec![broadcast_to_shape(&xs[0], &self.shape)]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let shape = xs[0].data().shape().to_vec();
+        vec![sum_to(&gys[0], &shape)]
+    }
+}
+
+pub fn broadcast_to<'c, A: Scalar>(x: &Variable<'c, A>, shape: &[usize]) -> Variable<'c, A>{
+    BroadcastTo{shape: shape.to_vec()}.call(&[x])[0].clone()
+}
+
+//sum_to
+struct SumTo{
+    shape: Vec<usize>
+}
+
+impl<A: Scalar> Function<A> for SumTo{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        vec![sum_to_shape(&xs[0], &self.shape)]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let shape = xs[0].data().shape().to_vec();
+        vec![broadcast_to(&gys[0], &shape)]
+    }
+}
+
+pub fn sum_to<'c, A: Scalar>(x: &Variable<'c, A>, shape: &[usize]) -> Variable<'c, A>{
+    SumTo{shape: shape.to_vec()}.call(&[x])[0].clone()
+}
+
+//Tensor<A, R> — a compile-time-ranked counterpart to `Variable`. `Variable`
+//wraps a dynamic `ArrayD`, so e.g. adding a rank-1 and a rank-2 `Variable`
+//only fails (or silently broadcasts) at runtime; here the rank is carried in
+//the type, the same way statically-sized-vector crates fix a dimension as a
+//const generic, so mismatched ranks are rejected at compile time instead.
+//Internally it's still just a `Variable` over an `ArrayD`; `from_array1`/
+//`from_array2` fix `R` at construction, and `into_dyn`/`try_from_dyn` bridge
+//back to the dynamic API for anything (e.g. `Conv1d`) that doesn't need it.
+#[derive(Clone)]
+pub struct Tensor<'c, A: Scalar, const R: usize>{
+    variable: Variable<'c, A>,
+}
+
+impl<'c, A: Scalar, const R: usize> Tensor<'c, A, R>{
+    pub fn into_dyn(self) -> Variable<'c, A>{
+        self.variable
+    }
+
+    pub fn as_variable(&self) -> &Variable<'c, A>{
+        &self.variable
+    }
+
+    pub fn try_from_dyn(variable: Variable<'c, A>) -> Result<Self, Variable<'c, A>>{
+        if variable.data().ndim() == R{
+            Ok(Tensor{variable})
+        } else{
+            Err(variable)
+        }
+    }
+
+    pub fn data(&self) -> Ref<ArrayD<A>>{
+        self.variable.data()
+    }
+
+    pub fn backward(&self, create_graph: bool){
+        self.variable.backward(create_graph);
+    }
+}
+
+impl<'c, A: Scalar> Tensor<'c, A, 1>{
+    pub fn from_array1(data: Array1<A>) -> Self{
+        Tensor{variable: Variable::new(data)}
+    }
+}
+
+impl<'c, A: Scalar> Tensor<'c, A, 2>{
+    pub fn from_array2(data: Array2<A>) -> Self{
+        Tensor{variable: Variable::new(data)}
+    }
+}
+
+impl<'c, A: Scalar, const R: usize> ops::Add<&Tensor<'c, A, R>> for &Tensor<'c, A, R>{
+    type Output = Tensor<'c, A, R>;
+    fn add(self, x: &Tensor<'c, A, R>) -> Tensor<'c, A, R>{
+        Tensor{variable: &self.variable + &x.variable}
+    }
+}
+
+impl<'c, A: Scalar, const R: usize> ops::Sub<&Tensor<'c, A, R>> for &Tensor<'c, A, R>{
+    type Output = Tensor<'c, A, R>;
+    fn sub(self, x: &Tensor<'c, A, R>) -> Tensor<'c, A, R>{
+        Tensor{variable: &self.variable - &x.variable}
+    }
+}
+
+impl<'c, A: Scalar, const R: usize> ops::Mul<&Tensor<'c, A, R>> for &Tensor<'c, A, R>{
+    type Output = Tensor<'c, A, R>;
+    fn mul(self, x: &Tensor<'c, A, R>) -> Tensor<'c, A, R>{
+        Tensor{variable: &self.variable * &x.variable}
+    }
+}
+
+impl<'c, A: Scalar + ops::Div<Output = A>, const R: usize> ops::Div<&Tensor<'c, A, R>> for &Tensor<'c, A, R>{
+    type Output = Tensor<'c, A, R>;
+    fn div(self, x: &Tensor<'c, A, R>) -> Tensor<'c, A, R>{
+        Tensor{variable: &self.variable / &x.variable}
+    }
+}
+
+#[test]
+fn test(){
+
+}
+
+#[test]
+fn double_backward_computes_second_derivative(){
+    let x = Variable::new(ndarray::arr1(&[2.0f64]));
+    let u = x.powf(2.0);
+    let y = u.powf(2.0);
+    y.backward(true);
+    let gx = x.grad().clone().unwrap();
+    x.cleargrad();
+    gx.backward(false);
+    let gx2 = x.grad().clone().unwrap();
+    assert_eq!(gx2.data().clone().into_raw_vec(), vec![48.0]);
 }
\ No newline at end of file
@@ -1,7 +1,7 @@
 use std::cell::Ref;
-use ndarray::ArrayD;
+use ndarray::{Array1, ArrayD, Ix1, Ix2};
 use num_traits::Float;
-use crate::core::{Variable, Function};
+use crate::core::{Scalar, Tensor, Variable, Function};
 
 pub struct Square;
 impl<A: Float> Function<A> for Square{
@@ -11,11 +11,11 @@ impl<A: Float> Function<A> for Square{
         vec![y]
     }
 
-    fn backward(&self, xs: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
         let x = &xs[0];
         let gy = &gys[0];
         let two = A::from(2).unwrap();
-        let gx = x.mapv(|x| x * two) * gy.mapv(|gy| gy);
+        let gx = &(x * two) * gy;
         vec![gx]
     }
 }
@@ -33,14 +33,313 @@ impl<A: Float> Function<A> for Exp{
         vec![y]
     }
 
-    fn backward(&self, xs: &[Ref<ArrayD<A>>], gys: &[ArrayD<A>]) -> Vec<ArrayD<A>> {
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
         let x = &xs[0];
         let gy = &gys[0];
-        let gx = x.mapv(|x| x.exp()) * gy.mapv(|gy| gy);
+        let gx = &exp(x) * gy;
         vec![gx]
     }
 }
 
 pub fn exp<'c,A: Float>(input: &Variable<'c,A>) -> Variable<'c,A>{
     Exp.call(&[input])[0].clone()
+}
+
+//matmul
+pub struct MatMul;
+impl<A: Float + 'static> Function<A> for MatMul{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = xs[0].view().into_dimensionality::<Ix2>().expect("MatMul: lhs must be 2-D");
+        let w = xs[1].view().into_dimensionality::<Ix2>().expect("MatMul: rhs must be 2-D");
+        assert_eq!(x.shape()[1], w.shape()[0],
+            "MatMul: shape mismatch ({:?})·({:?})", x.shape(), w.shape());
+        let y = x.dot(&w);
+        vec![y.into_dyn()]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let gy = &gys[0];
+        let gx = matmul(gy, &transpose(&xs[1]));
+        let gw = matmul(&transpose(&xs[0]), gy);
+        vec![gx, gw]
+    }
+}
+
+pub fn matmul<'c, A: Float + 'static>(x: &Variable<'c, A>, w: &Variable<'c, A>) -> Variable<'c, A>{
+    MatMul.call(&[x, w])[0].clone()
+}
+
+//`matmul` on the const-generic `Tensor` is an inherent method rather than an
+//`ops::Mul` impl: `*` on `Tensor` already means elementwise multiply (see
+//core.rs), same as it does on `Variable`, so the rank-2-only matrix product
+//needs its own name, same as the dynamic API's `matmul` free function.
+impl<'c, A: Float + 'static> Tensor<'c, A, 2>{
+    pub fn matmul(&self, w: &Tensor<'c, A, 2>) -> Tensor<'c, A, 2>{
+        Tensor::try_from_dyn(matmul(self.as_variable(), w.as_variable()))
+            .unwrap_or_else(|_| panic!("Tensor::matmul: result was not rank-2"))
+    }
+}
+
+//transpose (used by MatMul's backward so gradients w.r.t. the matrices flow
+//through the same Function machinery, keeping matmul differentiable twice)
+struct Transpose;
+impl<A: Float> Function<A> for Transpose{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = xs[0].view().into_dimensionality::<Ix2>().expect("Transpose: input must be 2-D");
+        vec![x.t().to_owned().into_dyn()]
+    }
+
+    fn backward<'c>(&self, _: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        vec![transpose(&gys[0])]
+    }
+}
+
+fn transpose<'c, A: Float>(x: &Variable<'c, A>) -> Variable<'c, A>{
+    Transpose.call(&[x])[0].clone()
+}
+
+//pad1d / narrow1d (adjoint pair, same idea as SumTo/BroadcastTo in core.rs:
+//padding with zeros and narrowing a slice back out are each other's backward)
+struct Pad1d{
+    left: usize,
+    right: usize,
+}
+
+impl<A: Scalar> Function<A> for Pad1d{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = xs[0].view().into_dimensionality::<Ix1>().expect("Pad1d: input must be 1-D");
+        let mut y = Array1::from_elem(self.left + x.len() + self.right, A::zero());
+        for (i, v) in x.iter().enumerate(){
+            y[self.left + i] = v.clone();
+        }
+        vec![y.into_dyn()]
+    }
+
+    fn backward<'c>(&self, _: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let len = gys[0].len() - self.left - self.right;
+        vec![narrow1d(&gys[0], self.left, len)]
+    }
+}
+
+fn pad1d<'c, A: Scalar>(x: &Variable<'c, A>, left: usize, right: usize) -> Variable<'c, A>{
+    Pad1d{left, right}.call(&[x])[0].clone()
+}
+
+struct Narrow1d{
+    start: usize,
+    len: usize,
+}
+
+impl<A: Scalar> Function<A> for Narrow1d{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = xs[0].view().into_dimensionality::<Ix1>().expect("Narrow1d: input must be 1-D");
+        vec![x.slice(ndarray::s![self.start..self.start + self.len]).to_owned().into_dyn()]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let total = xs[0].len();
+        vec![pad1d(&gys[0], self.start, total - self.start - self.len)]
+    }
+}
+
+fn narrow1d<'c, A: Scalar>(x: &Variable<'c, A>, start: usize, len: usize) -> Variable<'c, A>{
+    Narrow1d{start, len}.call(&[x])[0].clone()
+}
+
+//reverse1d (its own adjoint: reversing the incoming gradient undoes reversing the input)
+struct Reverse1d;
+impl<A: Scalar> Function<A> for Reverse1d{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let x = xs[0].view().into_dimensionality::<Ix1>().expect("Reverse1d: input must be 1-D");
+        let y: Array1<A> = x.iter().rev().cloned().collect();
+        vec![y.into_dyn()]
+    }
+
+    fn backward<'c>(&self, _: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        vec![reverse1d(&gys[0])]
+    }
+}
+
+fn reverse1d<'c, A: Scalar>(x: &Variable<'c, A>) -> Variable<'c, A>{
+    Reverse1d.call(&[x])[0].clone()
+}
+
+//conv1d
+//
+//Numeric core lives on plain `f64` buffers (`convolve_full` picks direct vs.
+//FFT internally); the `Function` wrapper below converts at the edges and
+//otherwise behaves like every other op in this file, building its backward
+//out of further `call`s (here: itself, `reverse1d`, and `narrow1d`) so
+//gradients-of-gradients keep working the same way `matmul`'s do.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Conv1dPadding{
+    Valid,
+    Full,
+}
+
+#[derive(Clone, Copy)]
+struct Complex64{
+    re: f64,
+    im: f64,
+}
+
+impl Complex64{
+    fn new(re: f64, im: f64) -> Self{ Complex64{re, im} }
+    fn add(self, o: Self) -> Self{ Complex64::new(self.re + o.re, self.im + o.im) }
+    fn sub(self, o: Self) -> Self{ Complex64::new(self.re - o.re, self.im - o.im) }
+    fn mul(self, o: Self) -> Self{
+        Complex64::new(self.re * o.re - self.im * o.im, self.re * o.im + self.im * o.re)
+    }
+}
+
+fn next_pow2(n: usize) -> usize{
+    let mut p = 1;
+    while p < n{
+        p <<= 1;
+    }
+    p
+}
+
+//iterative radix-2 Cooley-Tukey FFT, in place; `len` must be a power of two
+fn fft(a: &mut [Complex64], invert: bool){
+    let n = a.len();
+    if n <= 1{
+        return;
+    }
+    let mut j = 0;
+    for i in 1..n{
+        let mut bit = n >> 1;
+        while j & bit != 0{
+            j ^= bit;
+            bit >>= 1;
+        }
+        j ^= bit;
+        if i < j{
+            a.swap(i, j);
+        }
+    }
+    let mut len = 2;
+    while len <= n{
+        let ang = 2.0 * std::f64::consts::PI / len as f64 * if invert{ -1.0 } else{ 1.0 };
+        let wlen = Complex64::new(ang.cos(), ang.sin());
+        let mut i = 0;
+        while i < n{
+            let mut w = Complex64::new(1.0, 0.0);
+            for k in 0..len / 2{
+                let u = a[i + k];
+                let v = a[i + k + len / 2].mul(w);
+                a[i + k] = u.add(v);
+                a[i + k + len / 2] = u.sub(v);
+                w = w.mul(wlen);
+            }
+            i += len;
+        }
+        len <<= 1;
+    }
+    if invert{
+        for x in a.iter_mut(){
+            x.re /= n as f64;
+            x.im /= n as f64;
+        }
+    }
+}
+
+//direct O(n*m) sliding-sum convolution, full mode (output length n+m-1)
+fn convolve_direct(signal: &[f64], kernel: &[f64]) -> Vec<f64>{
+    let (n, m) = (signal.len(), kernel.len());
+    let mut y = vec![0.0; n + m - 1];
+    for (i, &s) in signal.iter().enumerate(){
+        for (j, &k) in kernel.iter().enumerate(){
+            y[i + j] += s * k;
+        }
+    }
+    y
+}
+
+//zero-pad both operands to N = next_pow2(n+m-1), transform, multiply pointwise,
+//inverse-transform, and keep the first n+m-1 (real) outputs
+fn convolve_fft(signal: &[f64], kernel: &[f64]) -> Vec<f64>{
+    let full_len = signal.len() + kernel.len() - 1;
+    let size = next_pow2(full_len);
+    let mut a: Vec<Complex64> = signal.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+    let mut b: Vec<Complex64> = kernel.iter().map(|&x| Complex64::new(x, 0.0)).collect();
+    a.resize(size, Complex64::new(0.0, 0.0));
+    b.resize(size, Complex64::new(0.0, 0.0));
+    fft(&mut a, false);
+    fft(&mut b, false);
+    for (x, y) in a.iter_mut().zip(b.iter()){
+        *x = x.mul(*y);
+    }
+    fft(&mut a, true);
+    a.truncate(full_len);
+    a.iter().map(|c| c.re).collect()
+}
+
+//direct costs O(n*m); the FFT path costs O(N log N) for N = next_pow2(n+m-1) plus
+//zero-padding overhead, so only take it once that's actually cheaper, and always
+//guard the overflowing-length case by falling back to direct.
+fn convolve_full(signal: &[f64], kernel: &[f64]) -> Vec<f64>{
+    let (n, m) = (signal.len(), kernel.len());
+    let full_len = n.checked_add(m).and_then(|s| s.checked_sub(1));
+    let direct_cost = n * m;
+    match full_len{
+        Some(full_len) =>{
+            let size = next_pow2(full_len);
+            let fft_cost = if size > 1{ size * (size as f64).log2().ceil() as usize } else{ 1 };
+            if fft_cost < direct_cost{
+                convolve_fft(signal, kernel)
+            } else{
+                convolve_direct(signal, kernel)
+            }
+        }
+        None => convolve_direct(signal, kernel),
+    }
+}
+
+fn to_f64_vec<A: Float>(x: &ArrayD<A>) -> Vec<f64>{
+    x.view().into_dimensionality::<Ix1>().expect("Conv1d: input must be 1-D")
+        .iter().map(|v| v.to_f64().unwrap()).collect()
+}
+
+fn from_f64_vec<A: Float>(v: &[f64]) -> ArrayD<A>{
+    Array1::from_vec(v.iter().map(|&x| A::from(x).unwrap()).collect()).into_dyn()
+}
+
+pub struct Conv1d;
+impl<A: Float> Function<A> for Conv1d{
+    fn forward(&self, xs: &[Ref<ArrayD<A>>]) -> Vec<ArrayD<A>> {
+        let signal = to_f64_vec(&xs[0]);
+        let kernel = to_f64_vec(&xs[1]);
+        vec![from_f64_vec::<A>(&convolve_full(&signal, &kernel))]
+    }
+
+    fn backward<'c>(&self, xs: &[Variable<'c, A>], gys: &[Variable<'c, A>]) -> Vec<Variable<'c, A>> {
+        let gy = &gys[0];
+        let n = xs[0].len();
+        let m = xs[1].len();
+        //gradient of a full convolution is itself a full convolution against the
+        //axis-reversed other operand, trimmed back down to the input's length
+        let gx_full = conv1d_full(gy, &reverse1d(&xs[1]));
+        let gx = narrow1d(&gx_full, m - 1, n);
+        let gw_full = conv1d_full(&reverse1d(&xs[0]), gy);
+        let gw = narrow1d(&gw_full, n - 1, m);
+        vec![gx, gw]
+    }
+}
+
+fn conv1d_full<'c, A: Float>(x: &Variable<'c, A>, w: &Variable<'c, A>) -> Variable<'c, A>{
+    Conv1d.call(&[x, w])[0].clone()
+}
+
+pub fn conv1d<'c, A: Float>(x: &Variable<'c, A>, w: &Variable<'c, A>, padding: Conv1dPadding) -> Variable<'c, A>{
+    let n = x.len();
+    let m = w.len();
+    let y = conv1d_full(x, w);
+    match padding{
+        Conv1dPadding::Full => y,
+        Conv1dPadding::Valid =>{
+            assert!(n >= m, "conv1d: valid padding requires the signal to be at least as long as the kernel");
+            narrow1d(&y, m - 1, n - m + 1)
+        }
+    }
 }
\ No newline at end of file